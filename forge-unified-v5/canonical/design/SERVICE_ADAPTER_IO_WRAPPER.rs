@@ -9,9 +9,76 @@ pub struct AdapterPlan {
     pub reads_env: bool,
     pub writes_logs: bool,
     pub does_io: bool,
+    pub uses_entropy: bool,
 }
 
 /// Canonical adapter: declare side effects explicitly (so patterns remain pure).
-pub fn describe_adapter_plan(reads_env: bool, writes_logs: bool, does_io: bool) -> AdapterPlan {
-    AdapterPlan { reads_env, writes_logs, does_io }
+pub fn describe_adapter_plan(reads_env: bool, writes_logs: bool, does_io: bool, uses_entropy: bool) -> AdapterPlan {
+    AdapterPlan { reads_env, writes_logs, does_io, uses_entropy }
+}
+
+/// Bitset of side effects an adapter is allowed to perform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capability(u8);
+
+impl Capability {
+    pub const NONE: Capability = Capability(0);
+    pub const ENV: Capability = Capability(1 << 0);
+    pub const LOGS: Capability = Capability(1 << 1);
+    pub const IO: Capability = Capability(1 << 2);
+    pub const ENTROPY: Capability = Capability(1 << 3);
+
+    pub fn union(self, other: Capability) -> Capability { Capability(self.0 | other.0) }
+    pub fn intersects(self, other: Capability) -> bool { self.0 & other.0 != 0 }
+    pub fn is_empty(self) -> bool { self.0 == 0 }
+}
+
+impl std::ops::BitOr for Capability {
+    type Output = Capability;
+    fn bitor(self, rhs: Capability) -> Capability { self.union(rhs) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapabilityError {
+    /// The plan grants capabilities the pattern never declared needing.
+    ExceedsDeclared(Capability),
+}
+
+impl AdapterPlan {
+    /// The effects this plan actually enables, as a `Capability` bitset.
+    pub fn capabilities(&self) -> Capability {
+        let mut caps = Capability::NONE;
+        if self.reads_env { caps = caps.union(Capability::ENV); }
+        if self.writes_logs { caps = caps.union(Capability::LOGS); }
+        if self.does_io { caps = caps.union(Capability::IO); }
+        if self.uses_entropy { caps = caps.union(Capability::ENTROPY); }
+        caps
+    }
+}
+
+/// Fails if `plan` grants any capability outside `needed` — e.g. a pattern
+/// that declares it needs only `Capability::ENTROPY` must be rejected if
+/// handed a plan that also enables `Capability::IO`, and a pattern marked
+/// fully pure (`needed = Capability::NONE`) must be rejected if handed any
+/// non-empty plan. `rotate_token` would call
+/// `require(plan, Capability::ENTROPY)` — requesting entropy while refusing
+/// `Capability::LOGS` (or any other capability) outright.
+pub fn require(plan: &AdapterPlan, needed: Capability) -> Result<(), CapabilityError> {
+    let granted = plan.capabilities();
+    let excess = Capability(granted.0 & !needed.0);
+    if !excess.is_empty() {
+        return Err(CapabilityError::ExceedsDeclared(excess));
+    }
+    Ok(())
+}
+
+/// Unions the effects of a nested adapter with its wrapper, so composed
+/// adapters accumulate their declared side effects.
+pub fn compose(inner: &AdapterPlan, outer: &AdapterPlan) -> AdapterPlan {
+    AdapterPlan {
+        reads_env: inner.reads_env || outer.reads_env,
+        writes_logs: inner.writes_logs || outer.writes_logs,
+        does_io: inner.does_io || outer.does_io,
+        uses_entropy: inner.uses_entropy || outer.uses_entropy,
+    }
 }
@@ -22,3 +22,62 @@ pub fn decide_idempotent_write(existing: Option<&RecordValue>, proposed: &Record
         _ => WriteDecision::UpsertNewValue,
     }
 }
+
+/// Upper bound, in bytes, on an encoded key or value's UTF-8 payload — the
+/// same cap `USER_VALIDATE_INPUT_SECURITY::MAX_ALLOWED` enforces on input
+/// strings, duplicated here since this block has no cross-file dependency.
+pub const MAX_ALLOWED: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    TooLong,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    Truncated,
+    TooLong,
+    InvalidUtf8,
+}
+
+/// Length-bounded wire form: a big-endian `u16` byte length followed by the
+/// UTF-8 payload. Mirrors the length-capped string encoding used for
+/// chain-name/version fields in peer protocols.
+pub fn encode_bounded(s: &str, max: usize) -> Result<Vec<u8>, EncodeError> {
+    let bytes = s.as_bytes();
+    if bytes.len() > max || bytes.len() > u16::MAX as usize {
+        return Err(EncodeError::TooLong);
+    }
+    let mut out = Vec::with_capacity(2 + bytes.len());
+    out.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+    out.extend_from_slice(bytes);
+    Ok(out)
+}
+
+/// Decodes a value framed by `encode_bounded`, returning it along with the
+/// number of bytes consumed from `buf`.
+pub fn decode_bounded(buf: &[u8], max: usize) -> Result<(String, usize), DecodeError> {
+    if buf.len() < 2 { return Err(DecodeError::Truncated); }
+    let len = u16::from_be_bytes([buf[0], buf[1]]) as usize;
+    if len > max { return Err(DecodeError::TooLong); }
+    let end = 2 + len;
+    if buf.len() < end { return Err(DecodeError::Truncated); }
+    let s = std::str::from_utf8(&buf[2..end]).map_err(|_| DecodeError::InvalidUtf8)?;
+    Ok((s.to_string(), end))
+}
+
+/// Frames a `RecordKey` followed by a `RecordValue`, each bounded by
+/// `MAX_ALLOWED` (see `USER_VALIDATE_INPUT_SECURITY`).
+pub fn encode_record(key: &RecordKey, value: &RecordValue) -> Result<Vec<u8>, EncodeError> {
+    let mut out = encode_bounded(&key.0, MAX_ALLOWED)?;
+    out.extend(encode_bounded(&value.0, MAX_ALLOWED)?);
+    Ok(out)
+}
+
+/// Decodes a `RecordKey`/`RecordValue` pair framed by `encode_record`,
+/// returning the pair and the number of bytes consumed from `buf`.
+pub fn decode_record(buf: &[u8]) -> Result<((RecordKey, RecordValue), usize), DecodeError> {
+    let (key, consumed_key) = decode_bounded(buf, MAX_ALLOWED)?;
+    let (value, consumed_value) = decode_bounded(&buf[consumed_key..], MAX_ALLOWED)?;
+    Ok(((RecordKey(key), RecordValue(value)), consumed_key + consumed_value))
+}
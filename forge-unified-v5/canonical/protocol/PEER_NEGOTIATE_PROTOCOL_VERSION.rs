@@ -0,0 +1,68 @@
+// PEER_NEGOTIATE_PROTOCOL_VERSION
+// peer negotiate protocol version
+
+/// Canonical Pattern Block (read-only)
+/// Constraints: deterministic, no IO, no logging, no config, single responsibility.
+
+/// The reasons a peer's `name` can fail the length/null checks applied
+/// before it takes part in a negotiation. Shaped like
+/// `USER_VALIDATE_INPUT_SECURITY::ValidationError`, but declared locally
+/// since this block has no cross-file dependency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    Empty,
+    TooLong,
+    ContainsNull,
+}
+
+const MAX_NAME_LEN: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolVersion {
+    name: String,
+    pub db_version: u16,
+    pub p2p_version: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NegotiationOutcome {
+    Compatible,
+    Incompatible { reason: String },
+    DowngradeTo { db_version: u16, p2p_version: u16 },
+}
+
+impl ProtocolVersion {
+    /// Validates `name` through the existing length/null checks before the
+    /// version can be used in a negotiation.
+    pub fn new(name: &str, db_version: u16, p2p_version: u16) -> Result<Self, ValidationError> {
+        if name.is_empty() { return Err(ValidationError::Empty); }
+        if name.len() > MAX_NAME_LEN { return Err(ValidationError::TooLong); }
+        if name.as_bytes().iter().any(|b| *b == 0) { return Err(ValidationError::ContainsNull); }
+        Ok(Self { name: name.to_string(), db_version, p2p_version })
+    }
+
+    pub fn name(&self) -> &str { &self.name }
+}
+
+/// Negotiates a common protocol version between a local and remote peer.
+/// Names must match exactly; db/p2p versions each downgrade independently
+/// to the lower of the two sides.
+pub fn negotiate(local: &ProtocolVersion, remote: &ProtocolVersion) -> NegotiationOutcome {
+    if local.name != remote.name {
+        return NegotiationOutcome::Incompatible {
+            reason: format!("chain name mismatch: {} != {}", local.name, remote.name),
+        };
+    }
+    let db_version = local.db_version.min(remote.db_version);
+    let p2p_version = local.p2p_version.min(remote.p2p_version);
+    if db_version < local.db_version || p2p_version < local.p2p_version {
+        NegotiationOutcome::DowngradeTo { db_version, p2p_version }
+    } else {
+        NegotiationOutcome::Compatible
+    }
+}
+
+/// Optional capability gated on the negotiated p2p version.
+pub fn supports_extended_nack(v: &ProtocolVersion) -> bool {
+    v.p2p_version > 0
+}
@@ -27,3 +27,210 @@ pub fn validate_input(input: &str) -> Result<ValidatedInput, ValidationError> {
     if input.as_bytes().iter().any(|b| *b == 0) { return Err(ValidationError::ContainsNull); }
     Ok(ValidatedInput::new(input))
 }
+
+/// Target type a `ValidatedInput` should be reparsed into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conversion {
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    Timestamp,
+    TimestampFmt(String),
+    /// Format plus an explicit UTC offset in seconds to apply to the parsed
+    /// (zone-less) fields — there is no IO-free way to discover the host's
+    /// actual local offset, so the caller must supply it.
+    TimestampTZFmt(String, i64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownConversion(pub String);
+
+impl std::str::FromStr for Conversion {
+    type Err = UnknownConversion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(fmt) = s.strip_prefix("timestamp|") {
+            return Ok(Conversion::TimestampFmt(fmt.to_string()));
+        }
+        if let Some(rest) = s.strip_prefix("timestamptz|") {
+            let (fmt, offset) = rest.rsplit_once('|').ok_or_else(|| UnknownConversion(s.to_string()))?;
+            let offset_seconds: i64 = offset.parse().map_err(|_| UnknownConversion(s.to_string()))?;
+            return Ok(Conversion::TimestampTZFmt(fmt.to_string(), offset_seconds));
+        }
+        match s {
+            "int" | "integer" => Ok(Conversion::Integer),
+            "float" => Ok(Conversion::Float),
+            "bool" | "boolean" => Ok(Conversion::Boolean),
+            "asis" | "bytes" | "string" => Ok(Conversion::Bytes),
+            "timestamp" => Ok(Conversion::Timestamp),
+            other => Err(UnknownConversion(other.to_string())),
+        }
+    }
+}
+
+/// Result of applying a `Conversion` to a `ValidatedInput`. Timestamps are
+/// stored as whole seconds since the Unix epoch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedValue {
+    Bytes(Vec<u8>),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Timestamp(i64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    Unknown(UnknownConversion),
+    InvalidInteger,
+    InvalidFloat,
+    InvalidBoolean,
+    InvalidTimestamp,
+}
+
+pub fn convert(input: &ValidatedInput, c: &Conversion) -> Result<TypedValue, ConversionError> {
+    let s = input.as_str();
+    match c {
+        Conversion::Bytes => Ok(TypedValue::Bytes(s.as_bytes().to_vec())),
+        Conversion::Integer => s.parse::<i64>().map(TypedValue::Integer).map_err(|_| ConversionError::InvalidInteger),
+        Conversion::Float => s.parse::<f64>().map(TypedValue::Float).map_err(|_| ConversionError::InvalidFloat),
+        Conversion::Boolean => match s {
+            "true" | "1" => Ok(TypedValue::Boolean(true)),
+            "false" | "0" => Ok(TypedValue::Boolean(false)),
+            _ => Err(ConversionError::InvalidBoolean),
+        },
+        Conversion::Timestamp => parse_iso8601(s).map(TypedValue::Timestamp).ok_or(ConversionError::InvalidTimestamp),
+        Conversion::TimestampFmt(fmt) => {
+            parse_with_format(s, fmt, 0).map(TypedValue::Timestamp).ok_or(ConversionError::InvalidTimestamp)
+        }
+        Conversion::TimestampTZFmt(fmt, offset_seconds) => {
+            // Format carries no zone, so the caller-supplied offset is
+            // subtracted from the parsed (zone-less) fields to recover UTC.
+            parse_with_format(s, fmt, *offset_seconds).map(TypedValue::Timestamp).ok_or(ConversionError::InvalidTimestamp)
+        }
+    }
+}
+
+/// Days since the Unix epoch for a proleptic Gregorian civil date
+/// (Howard Hinnant's `days_from_civil` algorithm).
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as i64;
+    let mp = (m + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn all_ascii_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parses an ISO8601/RFC3339 timestamp (`YYYY-MM-DDTHH:MM:SS[.fff][Z|±HH:MM]`,
+/// a space is also accepted in place of `T`). Rejects malformed separators,
+/// out-of-range date/time fields, and returns `None` rather than silently
+/// computing a bogus epoch value.
+fn parse_iso8601(s: &str) -> Option<i64> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 19 { return None; }
+    if bytes[4] != b'-' || bytes[7] != b'-' { return None; }
+    if bytes[10] != b'T' && bytes[10] != b' ' { return None; }
+    if bytes[13] != b':' || bytes[16] != b':' { return None; }
+
+    let y_s = s.get(0..4)?;
+    let mo_s = s.get(5..7)?;
+    let d_s = s.get(8..10)?;
+    let h_s = s.get(11..13)?;
+    let mi_s = s.get(14..16)?;
+    let se_s = s.get(17..19)?;
+    if ![y_s, mo_s, d_s, h_s, mi_s, se_s].iter().all(|field| all_ascii_digits(field)) {
+        return None;
+    }
+    let y: i64 = y_s.parse().ok()?;
+    let mo: i64 = mo_s.parse().ok()?;
+    let d: i64 = d_s.parse().ok()?;
+    let h: i64 = h_s.parse().ok()?;
+    let mi: i64 = mi_s.parse().ok()?;
+    let se: i64 = se_s.parse().ok()?;
+    if !(1..=12).contains(&mo) { return None; }
+    let leap = (y % 4 == 0 && y % 100 != 0) || y % 400 == 0;
+    let days_in_month = match mo {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if !(1..=days_in_month).contains(&d) { return None; }
+    if !(0..=23).contains(&h) { return None; }
+    if !(0..=59).contains(&mi) { return None; }
+    if !(0..=59).contains(&se) { return None; }
+
+    let mut rest = &s[19..];
+    if let Some(frac) = rest.strip_prefix('.') {
+        let digits_len = frac.bytes().take_while(|b| b.is_ascii_digit()).count();
+        if digits_len == 0 { return None; }
+        rest = &frac[digits_len..];
+    }
+    let offset_seconds = if rest.is_empty() || rest == "Z" {
+        0
+    } else {
+        let sign = match rest.as_bytes().first()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        let digits: String = rest[1..].chars().filter(|c| *c != ':').collect();
+        if digits.len() != 4 || !all_ascii_digits(&digits) { return None; }
+        let oh: i64 = digits[0..2].parse().ok()?;
+        let om: i64 = digits[2..4].parse().ok()?;
+        if oh > 23 || om > 59 { return None; }
+        sign * (oh * 3600 + om * 60)
+    };
+    let days = days_from_civil(y, mo, d);
+    Some(days * 86_400 + h * 3600 + mi * 60 + se - offset_seconds)
+}
+
+/// Minimal strftime-style parser supporting `%Y %m %d %H %M %S`; all other
+/// characters in `fmt` must match the input literally.
+fn parse_with_format(s: &str, fmt: &str, offset_seconds: i64) -> Option<i64> {
+    let mut y = 1970i64;
+    let mut mo = 1i64;
+    let mut d = 1i64;
+    let mut h = 0i64;
+    let mut mi = 0i64;
+    let mut se = 0i64;
+
+    let mut si = s.chars().peekable();
+    let mut fi = fmt.chars().peekable();
+    while let Some(fc) = fi.next() {
+        if fc == '%' {
+            let spec = fi.next()?;
+            let width = match spec { 'Y' => 4, _ => 2 };
+            let mut digits = String::new();
+            for _ in 0..width {
+                match si.peek() {
+                    Some(c) if c.is_ascii_digit() => digits.push(si.next()?),
+                    _ => break,
+                }
+            }
+            if digits.is_empty() { return None; }
+            let v: i64 = digits.parse().ok()?;
+            match spec {
+                'Y' => y = v,
+                'm' => mo = v,
+                'd' => d = v,
+                'H' => h = v,
+                'M' => mi = v,
+                'S' => se = v,
+                _ => return None,
+            }
+        } else if Some(fc) != si.next() {
+            return None;
+        }
+    }
+    if si.next().is_some() { return None; }
+    let days = days_from_civil(y, mo, d);
+    Some(days * 86_400 + h * 3600 + mi * 60 + se - offset_seconds)
+}